@@ -44,11 +44,46 @@
     return { valid: true, value: normalized };
   }
 
+  // 后端命令错误为 { code, messageKey, context, message }；message 已由后端按当前语言翻译，
+  // 错误文案只维护在后端一份
+  function describeError(error) {
+    if (error && typeof error === "object" && typeof error.code === "string") {
+      return error.message || error.code;
+    }
+    if (error instanceof Error) {
+      return error.message;
+    }
+    return String(error);
+  }
+
+  // 状态中的 Key 和配置列表中的 Key 用同样的方式脱敏 (含指纹)，相同即为同一个 Key
+  function findProfileByMaskedKey(profiles, maskedKey) {
+    if (!maskedKey || !Array.isArray(profiles)) {
+      return null;
+    }
+    return profiles.find((profile) => profile && profile.apiKey === maskedKey) || null;
+  }
+
+  // 固定快捷键的配置：去掉空项、重复项和已删除的配置，最多 9 个 (对应数字 1–9)
+  function normalizePinnedProfiles(ids, profiles) {
+    const known = new Set((Array.isArray(profiles) ? profiles : []).map((profile) => profile && profile.id));
+    const pinned = [];
+    for (const id of Array.isArray(ids) ? ids : []) {
+      if (id && known.has(id) && !pinned.includes(id)) {
+        pinned.push(id);
+      }
+    }
+    return pinned.slice(0, 9);
+  }
+
   return {
     shouldAutoOpenUsageGuide,
     getUpdateActionMode,
     formatVersionTag,
     getEditorPathMode,
     validateEditorPathInput,
+    describeError,
+    findProfileByMaskedKey,
+    normalizePinnedProfiles,
   };
 });
@@ -19,6 +19,8 @@ const { listen } = tauriApi.event;
 const LANG_STORAGE_KEY = "varswitch.lang";
 const THEME_STORAGE_KEY = "varswitch.theme";
 const APP_REPOSITORY_URL = "https://github.com/ConcertoNotes/variable-switching";
+const COPY_ICON = `<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect x="9" y="9" width="13" height="13" rx="2" ry="2"/><path d="M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1"/></svg>`;
+const EYE_ICON = `<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M1 12s4-8 11-8 11 8 11 8-4 8-11 8-11-8-11-8z"/><circle cx="12" cy="12" r="3"/></svg>`;
 
 const I18N = {
   en: {
@@ -59,6 +61,8 @@ const I18N = {
     addFirstConfig: "Add your first config",
     inUse: "In Use",
     switchUse: "Switch",
+    revealKey: "Show full key",
+    copyKey: "Copy key",
     edit: "Edit",
     delete: "Delete",
     toastUpdated: "Config updated",
@@ -208,6 +212,40 @@ const I18N = {
     toastImportNone: "No new profiles to import",
     settingsSilentStart: "Silent startup",
     settingsSilentStartDesc: "Start minimized to system tray",
+    settingsControlApi: "Local control API",
+    settingsControlApiDesc: "Let scripts on this computer query and switch configs (127.0.0.1, token in control.json). Takes effect after restart",
+    settingsGroupClaude: "Claude Code",
+    settingsClaudeLayer: "Settings layer",
+    settingsClaudeLayerDesc: "Which Claude settings file a switch writes to",
+    settingsClaudeLayerUser: "User (~/.claude/settings.json)",
+    settingsClaudeLayerProject: "Project (.claude/settings.json)",
+    settingsClaudeLayerLocal: "Local (.claude/settings.local.json)",
+    settingsClaudeProjectDir: "Project directory",
+    settingsClaudeProjectDirChoose: "Choose",
+    settingsApiKeyHelperMode: "apiKeyHelper mode",
+    settingsApiKeyHelperModeDesc: "Claude asks VarSwitch for the key instead of reading it from files and environment variables",
+    settingsGroupHotkeys: "Hotkeys",
+    settingsGroupNotifications: "Notifications",
+    settingsAutoCheckUpdates: "Check for updates automatically",
+    settingsAutoCheckUpdatesDesc: "Check GitHub for a new version once a day in the background",
+    settingsNotifySwitchResult: "Switch results",
+    settingsNotifySwitchResultDesc: "When a switch from the tray or a hotkey finishes",
+    settingsNotifyDrift: "Config changed externally",
+    settingsNotifyDriftDesc: "When the active config is no longer in effect",
+    settingsNotifyUpdateAvailable: "New version available",
+    settingsNotifyUpdateAvailableDesc: "Requires automatic update checks",
+    settingsNotifyUpdateFailed: "Update check failed",
+    settingsNotifyUpdateFailedDesc: "Shown once until a check succeeds again",
+    settingsNotifyHotkey: "Hotkey problems",
+    settingsNotifyHotkeyDesc: "When a hotkey cannot be registered or has no config to switch to",
+    settingsHotkeysEnabled: "Global hotkeys",
+    settingsHotkeysEnabledDesc: "Switch configs from anywhere with keyboard shortcuts",
+    settingsHotkeyNext: "Next config",
+    settingsHotkeyPrevious: "Previous config",
+    settingsHotkeyPinnedModifiers: "Pinned config modifiers",
+    settingsHotkeyPinnedModifiersDesc: "Combined with 1–9 to switch to the pinned configs below",
+    settingsHotkeyPinnedSlot: "Pinned {slot} ({shortcut})",
+    settingsHotkeyPinnedNone: "Not pinned",
     settingsLang: "Language",
     settingsTheme: "Theme",
     supportSectionTitle: "Quick Actions",
@@ -277,6 +315,8 @@ const I18N = {
     addFirstConfig: "添加第一个配置",
     inUse: "使用中",
     switchUse: "切换使用",
+    revealKey: "显示完整 Key",
+    copyKey: "复制 Key",
     edit: "编辑",
     delete: "删除",
     toastUpdated: "配置已更新",
@@ -424,6 +464,40 @@ const I18N = {
     toastImportNone: "没有新配置可导入",
     settingsSilentStart: "静默启动",
     settingsSilentStartDesc: "启动时最小化到系统托盘",
+    settingsControlApi: "本地控制接口",
+    settingsControlApiDesc: "允许本机脚本查询和切换配置 (127.0.0.1，令牌在 control.json 中)，重启后生效",
+    settingsGroupClaude: "Claude Code",
+    settingsClaudeLayer: "设置层级",
+    settingsClaudeLayerDesc: "切换时写入哪个 Claude 设置文件",
+    settingsClaudeLayerUser: "用户 (~/.claude/settings.json)",
+    settingsClaudeLayerProject: "项目 (.claude/settings.json)",
+    settingsClaudeLayerLocal: "本地 (.claude/settings.local.json)",
+    settingsClaudeProjectDir: "项目目录",
+    settingsClaudeProjectDirChoose: "选择",
+    settingsApiKeyHelperMode: "apiKeyHelper 模式",
+    settingsApiKeyHelperModeDesc: "由 Claude 向 VarSwitch 获取 Key，不再把明文 Key 写入文件和环境变量",
+    settingsGroupHotkeys: "快捷键",
+    settingsGroupNotifications: "通知",
+    settingsAutoCheckUpdates: "自动检查更新",
+    settingsAutoCheckUpdatesDesc: "每天在后台从 GitHub 检查一次新版本",
+    settingsNotifySwitchResult: "切换结果",
+    settingsNotifySwitchResultDesc: "通过托盘或快捷键切换完成时",
+    settingsNotifyDrift: "配置被外部修改",
+    settingsNotifyDriftDesc: "当前配置不再生效时",
+    settingsNotifyUpdateAvailable: "有新版本",
+    settingsNotifyUpdateAvailableDesc: "需开启自动检查更新",
+    settingsNotifyUpdateFailed: "检查更新失败",
+    settingsNotifyUpdateFailedDesc: "连续失败只提醒一次，直到再次检查成功",
+    settingsNotifyHotkey: "快捷键问题",
+    settingsNotifyHotkeyDesc: "快捷键无法注册或没有可切换的配置时",
+    settingsHotkeysEnabled: "全局快捷键",
+    settingsHotkeysEnabledDesc: "在任意位置通过快捷键切换配置",
+    settingsHotkeyNext: "下一个配置",
+    settingsHotkeyPrevious: "上一个配置",
+    settingsHotkeyPinnedModifiers: "固定配置修饰键",
+    settingsHotkeyPinnedModifiersDesc: "与数字 1–9 组合，切换到下方固定的配置",
+    settingsHotkeyPinnedSlot: "固定 {slot} ({shortcut})",
+    settingsHotkeyPinnedNone: "未固定",
     settingsLang: "语言",
     settingsTheme: "主题",
     supportSectionTitle: "快捷操作",
@@ -515,6 +589,8 @@ function esc(s) {
 }
 
 function maskKey(key) {
+  // 后端返回的 Key 已脱敏 (前缀…后四位 #指纹)
+  if (key && key.includes("…")) return key;
   if (!key || key.length < 12) return key || "--";
   return `${key.slice(0, 6)}****${key.slice(-4)}`;
 }
@@ -568,6 +644,13 @@ function validateEditorPathInput(value) {
   return { valid: true, value: normalized };
 }
 
+function errorText(error) {
+  if (typeof helpers.describeError === "function") {
+    return helpers.describeError(error);
+  }
+  return error?.message || String(error);
+}
+
 function syncAppSettingsAppearance() {
   if (!appSettings) return;
   appSettings.language = currentLang;
@@ -654,7 +737,7 @@ async function handleNeverShowUsageGuide() {
     closeUsageGuide();
     showToast(t("toastGuideDisabled"), "success");
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -684,7 +767,7 @@ async function checkForUpdates() {
       showToast(t("toastAlreadyLatest"), "success");
     }
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   } finally {
     updateBusy = false;
     updateBusyAction = null;
@@ -698,7 +781,7 @@ async function openUpdateReleasePage() {
     await invoke("open_external_target", { target });
     showToast(t("toastReleaseOpened"), "success");
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -717,7 +800,7 @@ async function openGitHubRepo() {
     await invoke("open_external_target", { target: APP_REPOSITORY_URL });
     showToast(t("toastRepoOpened"), "success");
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -878,8 +961,31 @@ function applyLanguage() {
   $("settingsImportBtn").textContent = t("settingsImport");
   $("settingsSilentStartLabel").textContent = t("settingsSilentStart");
   $("settingsSilentStartDesc").textContent = t("settingsSilentStartDesc");
+  $("settingsControlApiLabel").textContent = t("settingsControlApi");
+  $("settingsControlApiDesc").textContent = t("settingsControlApiDesc");
+  $("settingsGroupClaude").textContent = t("settingsGroupClaude");
+  $("settingsClaudeLayerLabel").textContent = t("settingsClaudeLayer");
+  $("settingsClaudeLayerDesc").textContent = t("settingsClaudeLayerDesc");
+  const layerSelect = $("settingsClaudeLayer");
+  layerSelect.options[0].textContent = t("settingsClaudeLayerUser");
+  layerSelect.options[1].textContent = t("settingsClaudeLayerProject");
+  layerSelect.options[2].textContent = t("settingsClaudeLayerLocal");
+  $("settingsClaudeProjectDirLabel").textContent = t("settingsClaudeProjectDir");
+  $("settingsClaudeProjectDirBrowse").textContent = t("settingsClaudeProjectDirChoose");
+  $("settingsApiKeyHelperModeLabel").textContent = t("settingsApiKeyHelperMode");
+  $("settingsApiKeyHelperModeDesc").textContent = t("settingsApiKeyHelperModeDesc");
+  $("settingsGroupHotkeys").textContent = t("settingsGroupHotkeys");
+  $("settingsGroupNotifications").textContent = t("settingsGroupNotifications");
+  $("settingsHotkeysEnabledLabel").textContent = t("settingsHotkeysEnabled");
+  $("settingsHotkeysEnabledDesc").textContent = t("settingsHotkeysEnabledDesc");
+  $("settingsHotkeyNextLabel").textContent = t("settingsHotkeyNext");
+  $("settingsHotkeyPreviousLabel").textContent = t("settingsHotkeyPrevious");
+  $("settingsHotkeyPinnedModifiersLabel").textContent = t("settingsHotkeyPinnedModifiers");
+  $("settingsHotkeyPinnedModifiersDesc").textContent = t("settingsHotkeyPinnedModifiersDesc");
   if ($("settingsOverlay").classList.contains("open")) {
     renderSettingsEditorPaths(getSettingsEditorPathInfos());
+    renderHotkeySettings();
+    renderNotificationSettings();
   }
 
   updateLangSegControl();
@@ -898,6 +1004,10 @@ function setLanguage(lang) {
   applyLanguage();
   renderProfiles();
   loadStatus();
+  // 同步到后端，托盘菜单和后端消息随之切换语言
+  if (appSettings) {
+    persistAppSettings().catch((error) => console.error("Failed to save language:", error));
+  }
 }
 
 function setTheme(theme) {
@@ -996,7 +1106,6 @@ async function loadStatus() {
     const allUrls = allLocations.map((l) => l.data?.baseUrl).filter(Boolean);
     const synced = allKeys.length > 0 && new Set(allKeys).size <= 1 && new Set(allUrls).size <= 1;
 
-    const COPY_ICON = `<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect x="9" y="9" width="13" height="13" rx="2" ry="2"/><path d="M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1"/></svg>`;
 
     // 渲染单张状态卡片
     function renderCard(loc, extraClass) {
@@ -1010,6 +1119,8 @@ async function loadStatus() {
             <div style="font-size:13px;color:var(--error-text)">${t("readFailed")}</div>
           </div>`;
       }
+      // 只能复制属于某个配置的 Key，完整 Key 需经确认后从后端取得
+      const keyProfile = helpers.findProfileByMaskedKey?.(profiles, item.apiKey);
       const badgeClass = synced ? "synced" : "unsynced";
       const badgeText = synced ? t("synced") : t("unsynced");
       const dotColor = synced ? "var(--success-text)" : "var(--warning-text)";
@@ -1026,7 +1137,7 @@ async function loadStatus() {
             <span class="status-label">${t("tokenLabel")}</span>
             <div class="status-value-wrapper">
               <span class="status-value">${maskKey(item.apiKey)}</span>
-              <button class="copy-btn" type="button" data-copy="${esc(item.apiKey || "")}" title="Copy">${COPY_ICON}</button>
+              ${keyProfile ? `<button class="copy-btn" type="button" data-copy-key="${esc(keyProfile.id)}" title="${t("copyKey")}">${COPY_ICON}</button>` : ""}
             </div>
           </div>
           <div class="status-item">
@@ -1089,6 +1200,11 @@ async function loadStatus() {
     grid.querySelectorAll(".copy-btn").forEach((btn) => {
       btn.addEventListener("click", (e) => {
         e.stopPropagation();
+        const keyProfileId = btn.getAttribute("data-copy-key");
+        if (keyProfileId) {
+          copyProfileKey(keyProfileId);
+          return;
+        }
         const text = btn.getAttribute("data-copy");
         if (text) {
           navigator.clipboard.writeText(text).then(() => {
@@ -1098,7 +1214,7 @@ async function loadStatus() {
       });
     });
   } catch (error) {
-    showToast(t("loadStatusFailed", { error: String(error) }), "error");
+    showToast(t("loadStatusFailed", { error: errorText(error) }), "error");
   }
 }
 
@@ -1108,7 +1224,7 @@ async function loadProfiles() {
     profiles = data.profiles || [];
     renderProfiles();
   } catch (error) {
-    showToast(t("loadProfilesFailed", { error: String(error) }), "error");
+    showToast(t("loadProfilesFailed", { error: errorText(error) }), "error");
   }
 }
 
@@ -1156,7 +1272,11 @@ function renderProfiles() {
       <div class="profile-body">
         <div class="profile-field">
           <span class="field-label">${t("tokenLabel")}</span>
-          <span class="field-value">${maskKey(profile.apiKey)}</span>
+          <div class="status-value-wrapper">
+            <span class="field-value" data-key-value="${profile.id}">${maskKey(profile.apiKey)}</span>
+            <button class="copy-btn" type="button" data-key-action="reveal" data-id="${profile.id}" title="${t("revealKey")}">${EYE_ICON}</button>
+            <button class="copy-btn" type="button" data-key-action="copy" data-id="${profile.id}" title="${t("copyKey")}">${COPY_ICON}</button>
+          </div>
         </div>
         <div class="profile-field">
           <span class="field-label">${t("urlLabel")}</span>
@@ -1187,9 +1307,47 @@ function renderProfiles() {
     });
   });
 
+  grid.querySelectorAll("button[data-key-action]").forEach((btn) => {
+    const id = btn.getAttribute("data-id");
+    btn.addEventListener("click", () => {
+      if (btn.getAttribute("data-key-action") === "copy") {
+        copyProfileKey(id);
+      } else {
+        showProfileKey(id);
+      }
+    });
+  });
+
   updateActiveConfigBar();
 }
 
+// 完整 Key 只通过 reveal_profile_key 取得，后端会先弹出系统确认框
+async function revealProfileKey(id) {
+  try {
+    return await invoke("reveal_profile_key", { id });
+  } catch (error) {
+    if (error?.code !== "Cancelled") {
+      showToast(errorText(error), "error");
+    }
+    return null;
+  }
+}
+
+async function copyProfileKey(id) {
+  const key = await revealProfileKey(id);
+  if (!key) return;
+  await navigator.clipboard.writeText(key);
+  showToast(t("toastCopied"), "success");
+}
+
+async function showProfileKey(id) {
+  const key = await revealProfileKey(id);
+  const field = document.querySelector(`[data-key-value="${CSS.escape(id)}"]`);
+  if (key && field) {
+    field.textContent = key;
+  }
+}
+
 function updateActiveConfigBar() {
   const section = $("activeConfigSection");
   const nameEl = $("activeConfigName");
@@ -1215,7 +1373,10 @@ function openModal(profile) {
   $("modalTitle").textContent = profile ? t("editConfig") : t("addConfig");
   $("profileId").value = editingId || "";
   $("profileName").value = profile ? profile.name : "";
-  $("profileApiKey").value = profile ? profile.apiKey : "";
+  // 后端只返回脱敏 Key：编辑时留空表示保持不变
+  $("profileApiKey").value = "";
+  $("profileApiKey").required = !profile;
+  $("profileApiKey").placeholder = profile ? profile.apiKey : t("placeholderApiKey");
   $("profileBaseUrl").value = profile ? profile.baseUrl : "";
   $("profileModelId").value = profile ? (profile.modelId || "") : "";
   $("modalOverlay").classList.add("open");
@@ -1248,7 +1409,7 @@ async function handleSubmit(event) {
     await loadProfiles();
     await loadStatus();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1262,7 +1423,7 @@ async function handleSwitch(id) {
     switchingSnapshot = await invoke("snapshot_config");
   } catch (error) {
     hideSwitchOverlay();
-    showToast(t("snapshotFailed", { error: String(error) }), "error");
+    showToast(t("snapshotFailed", { error: errorText(error) }), "error");
     return;
   }
 
@@ -1287,10 +1448,10 @@ async function handleSwitch(id) {
 
     if (result.cancelled) {
       try {
-        await invoke("restore_config", { snapshot: switchingSnapshot });
+        await invoke("restore_config", { snapshotId: switchingSnapshot.id });
         showToast(t("cancelledRestored"), "warning");
       } catch (restoreError) {
-        showToast(t("cancelRestoreFailed", { error: String(restoreError) }), "error");
+        showToast(t("cancelRestoreFailed", { error: errorText(restoreError) }), "error");
       }
     } else if (result.success) {
       showToast(t("switchedTo", { name: result.profileName }), "success");
@@ -1315,7 +1476,7 @@ async function handleSwitch(id) {
     }
   } catch (error) {
     hideSwitchOverlay();
-    showToast(t("switchFailed", { error: String(error) }), "error");
+    showToast(t("switchFailed", { error: errorText(error) }), "error");
   } finally {
     if (progressUnlisten) {
       progressUnlisten();
@@ -1333,7 +1494,7 @@ async function handleCancelSwitch() {
   try {
     await invoke("cancel_switch");
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1361,7 +1522,7 @@ async function handleDelete(id) {
     await loadProfiles();
     await loadStatus();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1376,7 +1537,7 @@ async function handleImport() {
     await loadProfiles();
     await loadStatus();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1411,7 +1572,7 @@ async function loadSkills() {
     skillsData = await invoke("get_skills");
     renderSkills();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1487,7 +1648,7 @@ async function handleSaveSkill() {
     hideSkillsEdit();
     await loadSkills();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1503,7 +1664,7 @@ async function handleDeleteSkill(name, sourceType) {
     showToast(t("toastSkillDeleted"), "success");
     await loadSkills();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1513,7 +1674,7 @@ async function loadSkillRepos() {
   try {
     skillRepos = await invoke("get_skill_repos");
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1550,7 +1711,7 @@ async function discoverSkillsFromRepos() {
     renderRepoFilter();
     renderDiscoverGrid();
   } catch (error) {
-    const errMsg = String(error);
+    const errMsg = errorText(error);
     showToast(errMsg, "error");
     $("discoverGrid").innerHTML = `<div class="discover-empty">${esc(errMsg)}</div>`;
   } finally {
@@ -1584,8 +1745,8 @@ async function searchGitHubSkills() {
       $("discoverGrid").innerHTML = `<div class="discover-empty">${t("discoverNoMatch")}</div>`;
     }
   } catch (error) {
-    showToast(String(error), "error");
-    $("discoverGrid").innerHTML = `<div class="discover-empty">${esc(String(error))}</div>`;
+    showToast(errorText(error), "error");
+    $("discoverGrid").innerHTML = `<div class="discover-empty">${esc(errorText(error))}</div>`;
   } finally {
     isDiscovering = false;
     $("discoverLoading").style.display = "none";
@@ -1678,7 +1839,7 @@ function renderDiscoverGrid() {
         renderDiscoverGrid();
         await loadSkills();
       } catch (error) {
-        showToast(String(error), "error");
+        showToast(errorText(error), "error");
         btn.disabled = false;
         btn.textContent = t("installBtn");
       }
@@ -1742,7 +1903,7 @@ async function handleAddRepo() {
     await loadSkillRepos();
     renderRepoList();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1753,7 +1914,7 @@ async function handleRemoveRepo(url) {
     await loadSkillRepos();
     renderRepoList();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1783,7 +1944,7 @@ async function loadClaudeMd() {
     const content = await invoke("get_claude_md");
     $("promptContentInput").value = content;
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1793,7 +1954,7 @@ async function loadPromptTemplates() {
     renderPromptTemplates();
     renderSnippetDropdown();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1864,7 +2025,7 @@ async function handleSavePrompt() {
     await invoke("save_claude_md", { content });
     showToast(t("toastPromptSaved"), "success");
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1887,7 +2048,7 @@ async function loadMcpServers() {
     mcpServers = await invoke("get_mcp_servers_list");
     renderMcpServers();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1966,7 +2127,7 @@ async function handleSaveMcp() {
     hideMcpEdit();
     await loadMcpServers();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -1982,7 +2143,7 @@ async function handleDeleteMcp(name) {
     showToast(t("toastMcpDeleted"), "success");
     await loadMcpServers();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -2009,7 +2170,7 @@ async function loadMcpPresets() {
     mcpGitHubResults = [];
     renderMcpPresets();
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -2029,8 +2190,8 @@ async function searchGitHubMcp() {
     mcpGitHubResults = results || [];
     renderMcpPresets();
   } catch (error) {
-    showToast(String(error), "error");
-    $("mcpPresetsGrid").innerHTML = `<div class="discover-empty">${esc(String(error))}</div>`;
+    showToast(errorText(error), "error");
+    $("mcpPresetsGrid").innerHTML = `<div class="discover-empty">${esc(errorText(error))}</div>`;
   } finally {
     $("mcpPresetLoading").style.display = "none";
   }
@@ -2089,7 +2250,7 @@ function renderMcpPresets() {
         await loadMcpServers();
         renderMcpPresets();
       } catch (error) {
-        showToast(String(error), "error");
+        showToast(errorText(error), "error");
         btn.disabled = false;
         btn.textContent = t("mcpInstallBtn");
       }
@@ -2401,6 +2562,10 @@ async function refreshSettingsPanelData() {
   $("settingsAutoStart").checked = !!appSettings.autoStart;
   $("settingsMinTray").checked = !!appSettings.minimizeToTray;
   $("settingsSilentStart").checked = !!appSettings.silentStartup;
+  $("settingsControlApi").checked = !!appSettings.controlApi;
+  renderClaudeSettings();
+  renderHotkeySettings();
+  renderNotificationSettings();
   $("settingsConfigDirValue").textContent = appPaths.configDir || "--";
   $("settingsClaudePathValue").textContent = appPaths.claudeSettings || "--";
   renderSettingsEditorPaths(getSettingsEditorPathInfos());
@@ -2424,7 +2589,7 @@ async function handleBrowseEditorPath(editorId) {
       input.value = selectedPath;
     }
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -2454,7 +2619,7 @@ async function handleSaveEditorPath(editorId) {
       "success"
     );
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -2475,7 +2640,7 @@ async function handleResetEditorPath(editorId) {
       "success"
     );
   } catch (error) {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   }
 }
 
@@ -2485,7 +2650,7 @@ function handleOpenEditorPath(editorId) {
   const target = (input?.value || "").trim() || editorInfo?.settingsPath;
   if (!target) return;
   invoke("open_folder", { path: target }).catch((error) => {
-    showToast(String(error), "error");
+    showToast(errorText(error), "error");
   });
 }
 
@@ -2507,12 +2672,195 @@ async function handleSettingsToggle() {
   appSettings.autoStart = $("settingsAutoStart").checked;
   appSettings.minimizeToTray = $("settingsMinTray").checked;
   appSettings.silentStartup = $("settingsSilentStart").checked;
+  appSettings.controlApi = $("settingsControlApi").checked;
   syncAppSettingsAppearance();
   try {
     await invoke("save_app_settings", { settings: appSettings });
     showToast(t("toastSettingsSaved"), "success");
   } catch (e) {
-    showToast(String(e), "error");
+    showToast(errorText(e), "error");
+  }
+}
+
+function renderClaudeSettings() {
+  $("settingsClaudeLayer").value = appSettings?.claudeSettingsLayer || "user";
+  $("settingsClaudeProjectDirValue").textContent = appSettings?.claudeProjectDir || "--";
+  $("settingsApiKeyHelperMode").checked = !!appSettings?.apiKeyHelperMode;
+}
+
+// 保存 Claude 相关设置；后端拒绝时 (如 apiKeyHelper 模式配 project 层级) 恢复原值并显示原因
+async function saveClaudeSettings(changes) {
+  if (!appSettings) return;
+  const previous = {
+    claudeSettingsLayer: appSettings.claudeSettingsLayer,
+    claudeProjectDir: appSettings.claudeProjectDir,
+    apiKeyHelperMode: appSettings.apiKeyHelperMode,
+  };
+  Object.assign(appSettings, changes);
+  try {
+    await invoke("save_app_settings", { settings: appSettings });
+    showToast(t("toastSettingsSaved"), "success");
+    loadStatus();
+  } catch (e) {
+    Object.assign(appSettings, previous);
+    showToast(errorText(e), "error");
+  }
+  renderClaudeSettings();
+}
+
+async function handleBrowseClaudeProjectDir() {
+  try {
+    const dialog = window.__TAURI_PLUGIN_DIALOG__;
+    const selectedPath = await dialog.open({
+      directory: true,
+      multiple: false,
+      defaultPath: appSettings?.claudeProjectDir || undefined,
+    });
+    if (!selectedPath || Array.isArray(selectedPath)) return;
+    await saveClaudeSettings({ claudeProjectDir: selectedPath });
+  } catch (error) {
+    showToast(errorText(error), "error");
+  }
+}
+
+function renderHotkeySettings() {
+  const hotkeys = appSettings?.hotkeys || {};
+  const enabled = !!hotkeys.enabled;
+  $("settingsHotkeysEnabled").checked = enabled;
+  $("settingsHotkeyNext").value = hotkeys.next || "";
+  $("settingsHotkeyPrevious").value = hotkeys.previous || "";
+  $("settingsHotkeyPinnedModifiers").value = hotkeys.pinnedModifiers || "";
+  for (const id of ["settingsHotkeyNext", "settingsHotkeyPrevious", "settingsHotkeyPinnedModifiers"]) {
+    $(id).disabled = !enabled;
+  }
+
+  // 已固定的配置各占一行，最后留一个空行用于追加
+  const pinned = helpers.normalizePinnedProfiles?.(hotkeys.pinnedProfiles, profiles) || [];
+  const slots = Math.min(pinned.length + 1, 9);
+  const container = $("settingsHotkeyPinned");
+  container.innerHTML = "";
+  for (let slot = 0; slot < slots; slot++) {
+    const row = document.createElement("div");
+    row.className = "settings-row";
+    const info = document.createElement("div");
+    info.className = "settings-row-info";
+    const label = document.createElement("div");
+    label.className = "settings-row-label";
+    const modifiers = hotkeys.pinnedModifiers || "";
+    label.textContent = t("settingsHotkeyPinnedSlot", {
+      slot: slot + 1,
+      shortcut: modifiers ? `${modifiers}+${slot + 1}` : "--",
+    });
+    info.appendChild(label);
+
+    const select = document.createElement("select");
+    select.className = "discover-filter settings-hotkey-pinned";
+    select.disabled = !enabled;
+    const none = document.createElement("option");
+    none.value = "";
+    none.textContent = t("settingsHotkeyPinnedNone");
+    select.appendChild(none);
+    for (const profile of profiles) {
+      const option = document.createElement("option");
+      option.value = profile.id;
+      option.textContent = profile.name;
+      select.appendChild(option);
+    }
+    select.value = pinned[slot] || "";
+    select.addEventListener("change", handleHotkeySettingsChange);
+
+    row.appendChild(info);
+    row.appendChild(select);
+    container.appendChild(row);
+  }
+}
+
+async function handleHotkeySettingsChange() {
+  if (!appSettings) return;
+  const pinnedValues = Array.from(document.querySelectorAll(".settings-hotkey-pinned")).map(
+    (select) => select.value
+  );
+  appSettings.hotkeys = {
+    ...(appSettings.hotkeys || {}),
+    enabled: $("settingsHotkeysEnabled").checked,
+    next: $("settingsHotkeyNext").value.trim(),
+    previous: $("settingsHotkeyPrevious").value.trim(),
+    pinnedModifiers: $("settingsHotkeyPinnedModifiers").value.trim(),
+    pinnedProfiles: helpers.normalizePinnedProfiles?.(pinnedValues, profiles) || [],
+  };
+  renderHotkeySettings();
+  try {
+    await invoke("save_app_settings", { settings: appSettings });
+    showToast(t("toastSettingsSaved"), "success");
+  } catch (error) {
+    // 设置已保存，但部分快捷键无法注册 (格式错误或被其他程序占用)
+    showToast(errorText(error), "error");
+  }
+}
+
+// 通知设置页的开关：autoCheckUpdates 在 AppSettings 顶层，其余在 notifications 中
+const NOTIFICATION_TOGGLES = [
+  { key: "autoCheckUpdates", label: "settingsAutoCheckUpdates" },
+  { key: "switchResult", label: "settingsNotifySwitchResult" },
+  { key: "drift", label: "settingsNotifyDrift" },
+  { key: "updateAvailable", label: "settingsNotifyUpdateAvailable", needsUpdateCheck: true },
+  { key: "updateFailed", label: "settingsNotifyUpdateFailed", needsUpdateCheck: true },
+  { key: "hotkey", label: "settingsNotifyHotkey" },
+];
+
+function notificationToggleValue(key) {
+  if (key === "autoCheckUpdates") return !!appSettings?.autoCheckUpdates;
+  return appSettings?.notifications?.[key] !== false;
+}
+
+function renderNotificationSettings() {
+  const container = $("settingsNotifications");
+  container.innerHTML = "";
+  for (const toggle of NOTIFICATION_TOGGLES) {
+    const row = document.createElement("div");
+    row.className = "settings-row";
+    const info = document.createElement("div");
+    info.className = "settings-row-info";
+    const label = document.createElement("div");
+    label.className = "settings-row-label";
+    label.textContent = t(toggle.label);
+    const desc = document.createElement("div");
+    desc.className = "settings-row-desc";
+    desc.textContent = t(`${toggle.label}Desc`);
+    info.appendChild(label);
+    info.appendChild(desc);
+
+    const switchLabel = document.createElement("label");
+    switchLabel.className = "toggle-switch";
+    const input = document.createElement("input");
+    input.type = "checkbox";
+    input.checked = notificationToggleValue(toggle.key);
+    input.disabled = !!toggle.needsUpdateCheck && !appSettings?.autoCheckUpdates;
+    input.addEventListener("change", () => handleNotificationToggle(toggle.key, input.checked));
+    const slider = document.createElement("span");
+    slider.className = "toggle-slider";
+    switchLabel.appendChild(input);
+    switchLabel.appendChild(slider);
+
+    row.appendChild(info);
+    row.appendChild(switchLabel);
+    container.appendChild(row);
+  }
+}
+
+async function handleNotificationToggle(key, checked) {
+  if (!appSettings) return;
+  if (key === "autoCheckUpdates") {
+    appSettings.autoCheckUpdates = checked;
+  } else {
+    appSettings.notifications = { ...(appSettings.notifications || {}), [key]: checked };
+  }
+  renderNotificationSettings();
+  try {
+    await invoke("save_app_settings", { settings: appSettings });
+    showToast(t("toastSettingsSaved"), "success");
+  } catch (error) {
+    showToast(errorText(error), "error");
   }
 }
 
@@ -2560,6 +2908,18 @@ $("settingsOverlay").addEventListener("click", (event) => {
 $("settingsAutoStart").addEventListener("change", handleSettingsToggle);
 $("settingsMinTray").addEventListener("change", handleSettingsToggle);
 $("settingsSilentStart").addEventListener("change", handleSettingsToggle);
+$("settingsControlApi").addEventListener("change", handleSettingsToggle);
+$("settingsClaudeLayer").addEventListener("change", (e) => {
+  saveClaudeSettings({ claudeSettingsLayer: e.target.value });
+});
+$("settingsClaudeProjectDirBrowse").addEventListener("click", handleBrowseClaudeProjectDir);
+$("settingsApiKeyHelperMode").addEventListener("change", (e) => {
+  saveClaudeSettings({ apiKeyHelperMode: e.target.checked });
+});
+$("settingsHotkeysEnabled").addEventListener("change", handleHotkeySettingsChange);
+$("settingsHotkeyNext").addEventListener("change", handleHotkeySettingsChange);
+$("settingsHotkeyPrevious").addEventListener("change", handleHotkeySettingsChange);
+$("settingsHotkeyPinnedModifiers").addEventListener("change", handleHotkeySettingsChange);
 $("settingsOpenConfigDir").addEventListener("click", () => {
   if (appPaths) invoke("open_folder", { path: appPaths.configDir });
 });
@@ -2579,9 +2939,17 @@ $("settingsImportBtn").addEventListener("click", handleImportProfiles);
   if (toolbar) toolbar.classList.add('app-hidden');
   if (appEl) appEl.classList.add('app-hidden');
 
-  await Promise.all([loadStatus(), loadProfiles(), loadAppSettings()]);
+  // 状态卡片要按配置列表匹配可复制的 Key，先加载配置
+  await Promise.all([loadProfiles(), loadAppSettings()]);
+  await loadStatus();
   renderUpdateButton();
 
+  // 托盘菜单中切换配置或检查状态后刷新界面
+  listen("profiles-changed", async () => {
+    await loadProfiles();
+    loadStatus();
+  });
+
   // 启动动画：等加载条填满后淡出
   const splash = $('splashScreen');
   if (splash) {
@@ -7,6 +7,9 @@ const {
   formatVersionTag,
   getEditorPathMode,
   validateEditorPathInput,
+  describeError,
+  findProfileByMaskedKey,
+  normalizePinnedProfiles,
 } = require("./app-helpers.js");
 
 test("shouldAutoOpenUsageGuide defaults to showing the guide", () => {
@@ -76,3 +79,36 @@ test("validateEditorPathInput rejects empty path drafts", () => {
     value: "C:/Users/test/AppData/Code/User",
   });
 });
+
+test("describeError shows the backend's localized message for structured errors", () => {
+  assert.equal(
+    describeError({ code: "NotFound", messageKey: "error.notFound.profile", context: { id: "p1" }, message: "配置未找到" }),
+    "配置未找到"
+  );
+  assert.equal(
+    describeError({ code: "Internal", messageKey: "error.internal", context: {}, message: "boom" }),
+    "boom"
+  );
+  assert.equal(describeError({ code: "Cancelled", messageKey: "error.cancelled", context: {} }), "Cancelled");
+  assert.equal(describeError("plain"), "plain");
+  assert.equal(describeError(new Error("oops")), "oops");
+});
+
+test("findProfileByMaskedKey matches only identical masked keys", () => {
+  const profiles = [
+    { id: "a", apiKey: "sk-ant…WXYZ #1a2b" },
+    { id: "b", apiKey: "sk-ant…WXYZ #9f8e" },
+  ];
+  assert.equal(findProfileByMaskedKey(profiles, "sk-ant…WXYZ #9f8e").id, "b");
+  assert.equal(findProfileByMaskedKey(profiles, "sk-ant…WXYZ #0000"), null);
+  assert.equal(findProfileByMaskedKey(profiles, ""), null);
+  assert.equal(findProfileByMaskedKey(null, "sk-ant…WXYZ #1a2b"), null);
+});
+
+test("normalizePinnedProfiles drops empty, duplicate and deleted profiles", () => {
+  const profiles = [{ id: "a" }, { id: "b" }, { id: "c" }];
+  assert.deepEqual(normalizePinnedProfiles(["b", "", "b", "gone", "a"], profiles), ["b", "a"]);
+  assert.deepEqual(normalizePinnedProfiles(null, profiles), []);
+  const many = Array.from({ length: 12 }, (_, i) => ({ id: `p${i}` }));
+  assert.equal(normalizePinnedProfiles(many.map((p) => p.id), many).length, 9);
+});